@@ -8,10 +8,15 @@ Usage:
     pip install openai
     export HF_TOKEN=your-token
     python basic_agent.py
+
+    # Print the answer as it streams
+    STREAM=1 python basic_agent.py
 """
 
 import os
-from openai import OpenAI
+import time
+from enum import IntEnum
+from openai import BadRequestError, OpenAI
 
 
 # Configure client with HuggingFace router endpoint
@@ -21,6 +26,19 @@ client = OpenAI(
 )
 
 
+class Verbosity(IntEnum):
+    """How much display_response prints (VERBOSITY env var)."""
+    QUIET = 0     # Final output text only
+    NORMAL = 1    # Response details and all output items
+    VERBOSE = 2   # Same as NORMAL; nothing is truncated in this example
+    TRACE = 3     # Also dump each raw output item
+
+
+def parse_verbosity(value: str) -> Verbosity:
+    """Parse a VERBOSITY value (quiet, normal, verbose, trace), defaulting to NORMAL."""
+    return Verbosity.__members__.get(value.strip().upper(), Verbosity.NORMAL)
+
+
 def create_basic_agent(model: str, input_text: str, instructions: str | None = None):
     """
     Create a basic agent request to Open Responses API.
@@ -32,7 +50,13 @@ def create_basic_agent(model: str, input_text: str, instructions: str | None = N
 
     Returns:
         Response with all output items
+
+    Raises:
+        ValueError: If the input is empty or whitespace-only
     """
+    if not input_text.strip():
+        raise ValueError("Input must not be empty")
+
     response = client.responses.create(
         model=model,
         instructions=instructions or "You are a helpful assistant.",
@@ -42,12 +66,124 @@ def create_basic_agent(model: str, input_text: str, instructions: str | None = N
     return response
 
 
-def display_response(response) -> None:
-    """Display the response in a readable format."""
+def stream_basic_agent(
+    model: str,
+    input_text: str,
+    instructions: str | None = None,
+    verbosity: Verbosity = Verbosity.NORMAL,
+):
+    """
+    Stream a basic agent request, printing text deltas as they arrive.
+
+    Args:
+        model: Model identifier with provider suffix (e.g., "model:groq")
+        input_text: The user's request
+        instructions: Optional system prompt
+        verbosity: How much detail to print besides the streamed text
+
+    Returns:
+        The completed response, or None if the provider rejected the
+        streaming request (400), so the caller can fall back
+
+    Raises:
+        RuntimeError: If the stream reports a failure or ends without a
+            completed response
+        ValueError: If the input is empty or whitespace-only
+    """
+    if not input_text.strip():
+        raise ValueError("Input must not be empty")
+
+    try:
+        stream = client.responses.create(
+            model=model,
+            instructions=instructions or "You are a helpful assistant.",
+            input=input_text,
+            stream=True,
+        )
+    except BadRequestError as e:
+        # Only a rejected request falls back; auth, rate limit and network errors propagate
+        if verbosity > Verbosity.QUIET:
+            print(f"Streaming unavailable ({e}), falling back to a single request")
+        return None
+
+    response = None
+    for event in stream:
+        match event.type:
+            case "response.output_text.delta":
+                print(event.delta, end="", flush=True)
+
+            case "response.completed":
+                response = event.response
+
+            case "response.failed":
+                error = getattr(event.response, "error", None)
+                print()
+                raise RuntimeError(f"Response failed: {getattr(error, 'message', None) or 'unknown error'}")
+
+            case "error":
+                print()
+                raise RuntimeError(f"Stream error: {getattr(event, 'message', None) or 'unknown error'}")
+
+    print()
+
+    if response is None:
+        raise RuntimeError("Stream ended without a response.completed event")
+
+    return response
+
+
+def tokens_per_second(output_tokens: int, duration_ms: int) -> float:
+    """Compute output throughput from token count and elapsed milliseconds."""
+    if duration_ms <= 0:
+        return 0.0
+    return output_tokens / (duration_ms / 1000)
+
+
+def output_token_split(usage) -> tuple[int, int] | None:
+    """
+    Split output tokens into reasoning and text.
+
+    Args:
+        usage: Response usage, possibly None
+
+    Returns:
+        (reasoning_tokens, text_tokens), or None when the provider
+        doesn't report output_tokens_details
+    """
+    details = getattr(usage, "output_tokens_details", None)
+    reasoning = getattr(details, "reasoning_tokens", None)
+    if reasoning is None:
+        return None
+    return reasoning, max(usage.output_tokens - reasoning, 0)
+
+
+def display_response(
+    response,
+    duration_ms: int | None = None,
+    verbosity: Verbosity = Verbosity.NORMAL,
+) -> None:
+    """
+    Display the response in a readable format.
+
+    Args:
+        response: The Open Responses response
+        duration_ms: Optional request latency, used to report tokens/sec
+        verbosity: How much detail to print
+    """
+    if verbosity == Verbosity.QUIET:
+        print(response.output_text)
+        return
+
     print(f"\n{'='*60}")
     print(f"Response ID: {response.id}")
     print(f"Model: {response.model}")
     print(f"Tokens: {response.usage.input_tokens if response.usage else 0} in / {response.usage.output_tokens if response.usage else 0} out")
+    split = output_token_split(response.usage)
+    if split:
+        print(f"  reasoning: {split[0]}")
+        print(f"  text: {split[1]}")
+    if duration_ms is not None and response.usage:
+        print(f"Latency: {duration_ms}ms ({tokens_per_second(response.usage.output_tokens, duration_ms):.1f} tokens/sec)")
     print(f"{'='*60}\n")
 
     # Use the convenience helper for simple text output
@@ -70,6 +206,29 @@ def display_response(response) -> None:
             case _:
                 print(f"[{item.type.upper()}] {item}")
 
+        if verbosity >= Verbosity.TRACE:
+            print(f"  Raw: {item.model_dump_json()}")
+
+
+def display_stream_summary(response, duration_ms: int) -> None:
+    """
+    Display the usage summary after a streamed response.
+
+    Args:
+        response: The completed Open Responses response
+        duration_ms: Time from request to the completed event
+    """
+    print(f"\n{'='*60}")
+    print(f"Response ID: {response.id}")
+    print(f"Tokens: {response.usage.input_tokens if response.usage else 0} in / {response.usage.output_tokens if response.usage else 0} out")
+    split = output_token_split(response.usage)
+    if split:
+        print(f"  reasoning: {split[0]}")
+        print(f"  text: {split[1]}")
+    if response.usage:
+        print(f"Latency: {duration_ms}ms ({tokens_per_second(response.usage.output_tokens, duration_ms):.1f} tokens/sec)")
+    print(f"{'='*60}")
+
 
 def main() -> None:
     """Main execution."""
@@ -81,16 +240,33 @@ def main() -> None:
         print("Error: HF_TOKEN environment variable required")
         exit(1)
 
-    print(f"Using model: {model}")
-    print(f"Endpoint: https://router.huggingface.co/v1/responses")
+    verbosity = parse_verbosity(os.environ.get("VERBOSITY", "normal"))
+
+    if verbosity > Verbosity.QUIET:
+        print(f"Using model: {model}")
+        print(f"Endpoint: https://router.huggingface.co/v1/responses")
+
+    input_text = "Explain the difference between TCP and UDP in simple terms."
 
     try:
-        result = create_basic_agent(
-            model=model,
-            input_text="Explain the difference between TCP and UDP in simple terms.",
-        )
+        # STREAM=1 prints text as it arrives instead of waiting for the full response
+        if os.environ.get("STREAM") == "1":
+            if verbosity > Verbosity.QUIET:
+                print()
+            start_time = time.time()
+            result = stream_basic_agent(model=model, input_text=input_text, verbosity=verbosity)
+            duration = int((time.time() - start_time) * 1000)
+
+            if result is not None:
+                if verbosity > Verbosity.QUIET:
+                    display_stream_summary(result, duration)
+                return
+
+        start_time = time.time()
+        result = create_basic_agent(model=model, input_text=input_text)
+        duration = int((time.time() - start_time) * 1000)
 
-        display_response(result)
+        display_response(result, duration, verbosity)
 
     except Exception as e:
         print(f"Error: {e}")
@@ -0,0 +1,136 @@
+"""
+Encrypted Reasoning Round-Trip Example - Open Responses API
+
+Demonstrates how to carry encrypted reasoning across turns.
+Proprietary models return reasoning as `encrypted_content`, which the
+client cannot read but can pass back so the model keeps its chain of
+thought without server-side state.
+
+There is no default model: the open-weight models used elsewhere in
+these examples expose RAW reasoning, so MODEL must name a model that
+returns `encrypted_content`.
+
+Usage:
+    pip install openai
+    export HF_TOKEN=your-token
+    export MODEL=<model-that-returns-encrypted-reasoning>
+    python encrypted_reasoning.py
+"""
+
+import os
+from openai import OpenAI
+
+
+# Configure client with HuggingFace router endpoint
+client = OpenAI(
+    base_url="https://router.huggingface.co/v1",
+    api_key=os.environ.get("HF_TOKEN"),
+)
+
+
+def collect_encrypted_reasoning(response) -> list[dict]:
+    """
+    Collect encrypted reasoning items so they can be sent back as input.
+
+    Item ids and order are preserved, since the provider matches
+    reasoning items by id when it decrypts them.
+
+    Args:
+        response: The Open Responses response
+
+    Returns:
+        List of reasoning items in the shape accepted by `input`
+    """
+    return [
+        item.model_dump(exclude_none=True)
+        for item in response.output
+        if item.type == "reasoning" and getattr(item, "encrypted_content", None)
+    ]
+
+
+def create_turn(model: str, input_items: list[dict]):
+    """
+    Send one stateless turn, asking for encrypted reasoning back.
+
+    Args:
+        model: Model identifier with provider suffix
+        input_items: Full conversation so far, including prior reasoning
+
+    Returns:
+        Response object
+
+    Raises:
+        ValueError: If there are no input items
+    """
+    if not input_items:
+        raise ValueError("Input must not be empty")
+
+    response = client.responses.create(
+        model=model,
+        instructions="You are a helpful assistant.",
+        input=input_items,
+        reasoning={"effort": "medium"},
+        include=["reasoning.encrypted_content"],
+        store=False,
+    )
+
+    return response
+
+
+def main() -> None:
+    """Main execution."""
+    # No default: open-weight models expose RAW reasoning and can't show the round trip
+    model = os.environ.get("MODEL")
+    hf_token = os.environ.get("HF_TOKEN")
+
+    if not hf_token:
+        print("Error: HF_TOKEN environment variable required")
+        exit(1)
+
+    if not model:
+        print("Error: MODEL environment variable required")
+        print("Set it to a model that returns encrypted reasoning (encrypted_content).")
+        exit(1)
+
+    print(f"Using model: {model}")
+    print(f"Endpoint: https://router.huggingface.co/v1/responses")
+
+    first_question = {"role": "user", "content": "Pick a prime number between 50 and 100 and explain why it is prime."}
+    follow_up = {"role": "user", "content": "Now double it and tell me whether the result is prime."}
+
+    try:
+        # Turn 1
+        first = create_turn(model, [first_question])
+        reasoning_items = collect_encrypted_reasoning(first)
+
+        print(f"\n{'='*60}")
+        print("TURN 1")
+        print(f"{'='*60}")
+        print(f"Encrypted reasoning items: {len(reasoning_items)}")
+        print(first.output_text)
+
+        if not reasoning_items:
+            print("\nNo encrypted reasoning returned - this model exposes raw or summary reasoning instead.")
+            print("Set MODEL to a model that returns encrypted_content to see the round trip.")
+
+        # Turn 2: replay the prior turn, including its encrypted reasoning
+        next_input = [
+            first_question,
+            *reasoning_items,
+            {"role": "assistant", "content": first.output_text},
+            follow_up,
+        ]
+        second = create_turn(model, next_input)
+
+        print(f"\n{'='*60}")
+        print("TURN 2")
+        print(f"{'='*60}")
+        print(second.output_text)
+
+    except Exception as e:
+        print(f"Error: {e}")
+        exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -12,6 +12,7 @@ Usage:
 
 import os
 import time
+from dataclasses import dataclass
 from openai import OpenAI
 
 
@@ -64,7 +65,13 @@ def create_agent(model: str, input_text: str, instructions: str | None = None):
 
     Returns:
         Response object
+
+    Raises:
+        ValueError: If the input is empty or whitespace-only
     """
+    if not input_text.strip():
+        raise ValueError("Input must not be empty")
+
     response = client.responses.create(
         model=model,
         instructions=instructions or "You are a helpful assistant.",
@@ -74,14 +81,39 @@ def create_agent(model: str, input_text: str, instructions: str | None = None):
     return response
 
 
-def compare_providers(prompt: str, models: list[str]) -> None:
+def truncate_text(text: str, max_chars: int) -> str:
+    """Truncate text to at most max_chars characters, marking the cut with an ellipsis."""
+    if len(text) <= max_chars:
+        return text
+    return text[:max_chars] + "…"
+
+
+@dataclass
+class DisplayOptions:
+    """Truncation limits for the provider comparison output (None = no limit)."""
+    reasoning_max: int | None = 150
+    tool_result_max: int | None = None
+    message_max: int | None = 300
+    truncate: bool = True  # False shows everything
+
+    def fit(self, text: str, max_chars: int | None) -> str:
+        """Apply a limit unless truncation is disabled."""
+        if not self.truncate or max_chars is None:
+            return text
+        return truncate_text(text, max_chars)
+
+
+def compare_providers(prompt: str, models: list[str], options: DisplayOptions | None = None) -> None:
     """
     Compare the same prompt across different providers.
 
     Args:
         prompt: The prompt to send
         models: List of model identifiers with provider suffixes
+        options: Truncation limits for reasoning and response text
     """
+    options = options or DisplayOptions()
+
     print("\n" + "=" * 70)
     print("MULTI-PROVIDER COMPARISON")
     print("=" * 70)
@@ -115,12 +147,12 @@ def compare_providers(prompt: str, models: list[str]) -> None:
                 print(f"\nReasoning ({len(reasoning_items)} items):")
                 for item in reasoning_items:
                     text = getattr(item, "content", None) or getattr(item, "summary", None) or "[no content]"
-                    print(f"  - {text[:150]}{'...' if len(text) > 150 else ''}")
+                    print(f"  - {options.fit(text, options.reasoning_max)}")
 
             # Display final response using convenience helper
             print(f"\nResponse:")
             output_text = response.output_text or ""
-            print(f"  {output_text[:300]}{'...' if len(output_text) > 300 else ''}")
+            print(f"  {options.fit(output_text, options.message_max)}")
 
             results.append({"model": model, "response": response, "duration": duration})
 
@@ -145,8 +177,15 @@ def compare_providers(prompt: str, models: list[str]) -> None:
             print(f"{suffix:12} | FAILED  | {result['error']}")
 
 
-def demonstrate_provider_switching() -> None:
-    """Demonstrate provider switching via model suffix."""
+def demonstrate_provider_switching(options: DisplayOptions | None = None) -> None:
+    """
+    Demonstrate provider switching via model suffix.
+
+    Args:
+        options: Truncation limits (reasoning defaults to 100 chars here)
+    """
+    options = options or DisplayOptions(reasoning_max=100)
+
     print("\n" + "=" * 70)
     print("PROVIDER SWITCHING DEMONSTRATION")
     print("=" * 70)
@@ -173,7 +212,7 @@ def demonstrate_provider_switching() -> None:
         match item.type:
             case "reasoning":
                 text = getattr(item, "content", None) or getattr(item, "summary", None) or "[no content]"
-                print(f"  [REASONING] {text[:100]}...")
+                print(f"  [REASONING] {options.fit(text, options.reasoning_max)}")
             case "message":
                 print(f"  [MESSAGE] {getattr(item, 'content', '')}")
             case _:
@@ -11,6 +11,9 @@ Usage:
 """
 
 import os
+import time
+from dataclasses import dataclass
+from enum import IntEnum
 from openai import OpenAI
 
 
@@ -21,6 +24,34 @@ client = OpenAI(
 )
 
 
+class Verbosity(IntEnum):
+    """How much the display functions print (VERBOSITY env var)."""
+    QUIET = 0     # Final output text only
+    NORMAL = 1    # Response items, long text truncated
+    VERBOSE = 2   # Full reasoning and tool results
+    TRACE = 3     # Also dump each raw output item
+
+
+def parse_verbosity(value: str) -> Verbosity:
+    """Parse a VERBOSITY value (quiet, normal, verbose, trace), defaulting to NORMAL."""
+    return Verbosity.__members__.get(value.strip().upper(), Verbosity.NORMAL)
+
+
+@dataclass
+class DisplayOptions:
+    """Truncation limits for display_execution_trace (None = no limit)."""
+    reasoning_max: int | None = 200
+    tool_result_max: int | None = 200
+    message_max: int | None = None
+    truncate: bool = True  # False shows everything
+
+    def fit(self, text: str, max_chars: int | None) -> str:
+        """Apply a limit unless truncation is disabled."""
+        if not self.truncate or max_chars is None:
+            return text
+        return truncate_text(text, max_chars)
+
+
 # Define tools for the agent
 # NOTE: Tools are defined at TOP LEVEL (name, description, parameters)
 # NOT nested inside a "function" object
@@ -112,6 +143,13 @@ tools = [
 ]
 
 
+def truncate_text(text: str, max_chars: int) -> str:
+    """Truncate text to at most max_chars characters, marking the cut with an ellipsis."""
+    if len(text) <= max_chars:
+        return text
+    return text[:max_chars] + "…"
+
+
 def create_agent_with_tools(model: str, input_text: str, instructions: str | None = None):
     """
     Create an agent with sub-agent loop capability.
@@ -123,10 +161,16 @@ def create_agent_with_tools(model: str, input_text: str, instructions: str | Non
 
     Returns:
         Response with all execution items
+
+    Raises:
+        ValueError: If the task is empty or whitespace-only
     """
+    if not input_text.strip():
+        raise ValueError("Input must not be empty")
+
     print(f"\n[REQUEST] Sending to HuggingFace router...")
     print(f"[MODEL] {model}")
-    print(f"[INPUT] {input_text[:100]}...")
+    print(f"[INPUT] {truncate_text(input_text, 100)}")
 
     response = client.responses.create(
         model=model,
@@ -139,14 +183,78 @@ def create_agent_with_tools(model: str, input_text: str, instructions: str | Non
     return response
 
 
-def display_execution_trace(response) -> None:
-    """Display the complete execution trace."""
+def tokens_per_second(output_tokens: int, duration_ms: int) -> float:
+    """Compute output throughput from token count and elapsed milliseconds."""
+    if duration_ms <= 0:
+        return 0.0
+    return output_tokens / (duration_ms / 1000)
+
+
+def summarize_response(response) -> dict:
+    """
+    Count the item types in a response.
+
+    Args:
+        response: The Open Responses response
+
+    Returns:
+        Dictionary with reasoning, tool call, tool result and message counts
+        plus total tokens
+    """
+    types = [item.type for item in response.output]
+
+    return {
+        "reasoning_steps": types.count("reasoning"),
+        "tool_calls": types.count("function_call"),
+        "tool_results": types.count("function_call_output"),
+        "messages": types.count("message"),
+        "total_tokens": response.usage.input_tokens + response.usage.output_tokens if response.usage else 0,
+    }
+
+
+def display_execution_trace(
+    response,
+    duration_ms: int | None = None,
+    verbosity: Verbosity = Verbosity.NORMAL,
+    options: DisplayOptions | None = None,
+) -> None:
+    """
+    Display the complete execution trace.
+
+    Args:
+        response: The Open Responses response
+        duration_ms: Optional request latency, used to report tokens/sec
+        verbosity: How much detail to print
+        options: Truncation limits (VERBOSE and above never truncate)
+    """
+    if verbosity == Verbosity.QUIET:
+        print(response.output_text)
+        return
+
+    options = options or DisplayOptions()
+    if verbosity >= Verbosity.VERBOSE:
+        options = DisplayOptions(truncate=False)
+
     print(f"\n{'='*60}")
     print(f"EXECUTION TRACE - {response.id}")
     print(f"{'='*60}")
+
+    # One-line summary first; it carries the token and latency figures
+    summary = summarize_response(response)
+    in_tokens = response.usage.input_tokens if response.usage else 0
+    out_tokens = response.usage.output_tokens if response.usage else 0
+    wall_time = ""
+    if duration_ms is not None:
+        wall_time = f", {duration_ms}ms"
+        if response.usage:
+            wall_time += f" ({tokens_per_second(response.usage.output_tokens, duration_ms):.1f} tokens/sec)"
+    print(
+        f"Summary: {summary['reasoning_steps']} reasoning steps, {summary['tool_calls']} tool calls, "
+        f"{summary['tool_results']} tool results, {summary['messages']} messages, "
+        f"{summary['total_tokens']} tokens ({in_tokens} in / {out_tokens} out){wall_time}"
+    )
     print(f"Model: {response.model}")
     print(f"Total output items: {len(response.output)}")
-    print(f"Tokens: {response.usage.input_tokens if response.usage else 0} in / {response.usage.output_tokens if response.usage else 0} out")
     print(f"{'='*60}\n")
 
     tool_call_count = 0
@@ -158,7 +266,7 @@ def display_execution_trace(response) -> None:
             case "reasoning":
                 text = getattr(item, "summary", None) or getattr(item, "content", None) or "[encrypted reasoning]"
                 print(f"{prefix} [REASONING]")
-                print(f"    {text[:200]}{'...' if len(text) > 200 else ''}")
+                print(f"    {options.fit(text, options.reasoning_max)}")
 
             case "function_call":
                 tool_call_count += 1
@@ -169,16 +277,19 @@ def display_execution_trace(response) -> None:
             case "function_call_output":
                 output = getattr(item, "output", "") or ""
                 print(f"{prefix} [TOOL RESULT]")
-                print(f"    Result: {output[:200]}{'...' if len(output) > 200 else ''}")
+                print(f"    Result: {options.fit(output, options.tool_result_max)}")
 
             case "message":
                 print(f"{prefix} [FINAL RESPONSE]")
-                print(f"    {getattr(item, 'content', '')}")
+                print(f"    {options.fit(str(getattr(item, 'content', '')), options.message_max)}")
 
             case _:
                 print(f"{prefix} [{item.type.upper()}]")
                 print(f"    {item}")
 
+        if verbosity >= Verbosity.TRACE:
+            print(f"    Raw: {item.model_dump_json()}")
+
         print()
 
     print(f"{'='*60}")
@@ -213,8 +324,12 @@ def main() -> None:
     """
 
     try:
+        start_time = time.time()
         result = create_agent_with_tools(model, task)
-        display_execution_trace(result)
+        duration = int((time.time() - start_time) * 1000)
+
+        verbosity = parse_verbosity(os.environ.get("VERBOSITY", "normal"))
+        display_execution_trace(result, duration, verbosity)
 
     except Exception as e:
         print(f"Error: {e}")
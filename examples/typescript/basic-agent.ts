@@ -8,6 +8,9 @@
  *     npm install openai
  *     export HF_TOKEN=your-token
  *     npx ts-node basic-agent.ts
+ *
+ *     # Print the answer as it streams
+ *     STREAM=1 npx ts-node basic-agent.ts
  */
 
 import OpenAI from "openai";
@@ -18,26 +21,90 @@ const client = new OpenAI({
   apiKey: process.env.HF_TOKEN,
 });
 
+/**
+ * How much createBasicAgent prints (VERBOSITY env var)
+ */
+enum Verbosity {
+  QUIET = 0, // Final output text only
+  NORMAL = 1, // Response details and all output items
+  VERBOSE = 2, // Same as NORMAL; nothing is truncated in this example
+  TRACE = 3, // Also dump each raw output item
+}
+
+/**
+ * Parse a VERBOSITY value (quiet, normal, verbose, trace), defaulting to NORMAL
+ */
+function parseVerbosity(value: string | undefined): Verbosity {
+  switch ((value || "").trim().toLowerCase()) {
+    case "quiet":
+      return Verbosity.QUIET;
+    case "verbose":
+      return Verbosity.VERBOSE;
+    case "trace":
+      return Verbosity.TRACE;
+    default:
+      return Verbosity.NORMAL;
+  }
+}
+
+/**
+ * Compute output throughput from token count and elapsed milliseconds
+ */
+function tokensPerSecond(outputTokens: number, durationMs: number): number {
+  if (durationMs <= 0) {
+    return 0;
+  }
+  return outputTokens / (durationMs / 1000);
+}
+
+/**
+ * Split output tokens into reasoning and text.
+ * Returns null when the provider doesn't report output_tokens_details.
+ */
+function outputTokenSplit(
+  usage?: OpenAI.Responses.ResponseUsage
+): { reasoning: number; text: number } | null {
+  const reasoning = usage?.output_tokens_details?.reasoning_tokens;
+  if (reasoning === undefined || reasoning === null) {
+    return null;
+  }
+  return { reasoning, text: Math.max(usage!.output_tokens - reasoning, 0) };
+}
+
 /**
  * Create a basic agent request to Open Responses API
  */
 async function createBasicAgent(
   model: string,
   input: string,
-  instructions?: string
+  instructions?: string,
+  verbosity: Verbosity = Verbosity.NORMAL
 ) {
-  console.log("=".repeat(60));
-  console.log("BASIC AGENT EXAMPLE");
-  console.log("=".repeat(60));
-  console.log(`Model: ${model}`);
-  console.log(`Input: ${input}`);
-  console.log("=".repeat(60));
+  if (!input.trim()) {
+    throw new Error("Input must not be empty");
+  }
+
+  if (verbosity !== Verbosity.QUIET) {
+    console.log("=".repeat(60));
+    console.log("BASIC AGENT EXAMPLE");
+    console.log("=".repeat(60));
+    console.log(`Model: ${model}`);
+    console.log(`Input: ${input}`);
+    console.log("=".repeat(60));
+  }
 
+  const startTime = Date.now();
   const response = await client.responses.create({
     model,
     instructions: instructions || "You are a helpful assistant.",
     input,
   });
+  const duration = Date.now() - startTime;
+
+  if (verbosity === Verbosity.QUIET) {
+    console.log(response.output_text);
+    return response;
+  }
 
   console.log(`\nResponse ID: ${response.id}`);
   console.log(`Model: ${response.model}`);
@@ -61,12 +128,113 @@ async function createBasicAgent(
       default:
         console.log(`[${item.type.toUpperCase()}]`, item);
     }
+
+    if (verbosity >= Verbosity.TRACE) {
+      console.log(`  Raw: ${JSON.stringify(item)}`);
+    }
   }
 
   // Token usage
   console.log("\n--- Usage ---");
   console.log(`Input tokens: ${response.usage?.input_tokens}`);
   console.log(`Output tokens: ${response.usage?.output_tokens}`);
+  const split = outputTokenSplit(response.usage);
+  if (split) {
+    console.log(`  reasoning: ${split.reasoning}`);
+    console.log(`  text: ${split.text}`);
+  }
+  if (response.usage) {
+    console.log(`Latency: ${duration}ms (${tokensPerSecond(response.usage.output_tokens, duration).toFixed(1)} tokens/sec)`);
+  }
+
+  return response;
+}
+
+/**
+ * Stream a basic agent request, printing text deltas as they arrive.
+ * Returns null if the provider rejected the streaming request (400), so the
+ * caller can fall back; throws if the stream reports a failure.
+ */
+async function streamBasicAgent(
+  model: string,
+  input: string,
+  instructions?: string,
+  verbosity: Verbosity = Verbosity.NORMAL
+): Promise<OpenAI.Responses.Response | null> {
+  if (!input.trim()) {
+    throw new Error("Input must not be empty");
+  }
+
+  if (verbosity !== Verbosity.QUIET) {
+    console.log("=".repeat(60));
+    console.log("BASIC AGENT EXAMPLE (STREAMING)");
+    console.log("=".repeat(60));
+    console.log(`Model: ${model}`);
+    console.log(`Input: ${input}`);
+    console.log("=".repeat(60) + "\n");
+  }
+
+  const startTime = Date.now();
+  let stream;
+  try {
+    stream = await client.responses.create({
+      model,
+      instructions: instructions || "You are a helpful assistant.",
+      input,
+      stream: true,
+    });
+  } catch (error) {
+    // Only a rejected request falls back; auth, rate limit and network errors propagate
+    if (!(error instanceof OpenAI.BadRequestError)) {
+      throw error;
+    }
+    if (verbosity !== Verbosity.QUIET) {
+      console.log(`Streaming unavailable (${error.message}), falling back to a single request\n`);
+    }
+    return null;
+  }
+
+  let response: OpenAI.Responses.Response | null = null;
+  for await (const event of stream) {
+    switch (event.type) {
+      case "response.output_text.delta":
+        process.stdout.write(event.delta);
+        break;
+      case "response.completed":
+        response = event.response;
+        break;
+      case "response.failed":
+        console.log();
+        throw new Error(`Response failed: ${event.response.error?.message || "unknown error"}`);
+      case "error":
+        console.log();
+        throw new Error(`Stream error: ${event.message || "unknown error"}`);
+    }
+  }
+  const duration = Date.now() - startTime;
+  console.log();
+
+  if (!response) {
+    throw new Error("Stream ended without a response.completed event");
+  }
+
+  if (verbosity === Verbosity.QUIET) {
+    return response;
+  }
+
+  // Token usage
+  console.log("\n--- Usage ---");
+  console.log(`Response ID: ${response.id}`);
+  console.log(`Input tokens: ${response.usage?.input_tokens}`);
+  console.log(`Output tokens: ${response.usage?.output_tokens}`);
+  const split = outputTokenSplit(response.usage);
+  if (split) {
+    console.log(`  reasoning: ${split.reasoning}`);
+    console.log(`  text: ${split.text}`);
+  }
+  if (response.usage) {
+    console.log(`Latency: ${duration}ms (${tokensPerSecond(response.usage.output_tokens, duration).toFixed(1)} tokens/sec)`);
+  }
 
   return response;
 }
@@ -81,14 +249,22 @@ async function main(): Promise<void> {
     process.exit(1);
   }
 
-  console.log(`Using model: ${model}`);
-  console.log(`Endpoint: https://router.huggingface.co/v1/responses`);
+  const verbosity = parseVerbosity(process.env.VERBOSITY);
+
+  if (verbosity !== Verbosity.QUIET) {
+    console.log(`Using model: ${model}`);
+    console.log(`Endpoint: https://router.huggingface.co/v1/responses`);
+  }
+
+  const input = "Explain the difference between TCP and UDP in simple terms.";
 
   try {
-    await createBasicAgent(
-      model,
-      "Explain the difference between TCP and UDP in simple terms."
-    );
+    // STREAM=1 prints text as it arrives instead of waiting for the full response
+    if (process.env.STREAM === "1" && (await streamBasicAgent(model, input, undefined, verbosity))) {
+      return;
+    }
+
+    await createBasicAgent(model, input, undefined, verbosity);
   } catch (error) {
     console.error("Error:", error);
     process.exit(1);
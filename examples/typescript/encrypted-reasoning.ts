@@ -0,0 +1,128 @@
+/**
+ * Encrypted Reasoning Round-Trip Example - Open Responses API
+ *
+ * Demonstrates how to carry encrypted reasoning across turns.
+ * Proprietary models return reasoning as `encrypted_content`, which the
+ * client cannot read but can pass back so the model keeps its chain of
+ * thought without server-side state.
+ *
+ * There is no default model: the open-weight models used elsewhere in
+ * these examples expose RAW reasoning, so MODEL must name a model that
+ * returns `encrypted_content`.
+ *
+ * Usage:
+ *     npm install openai
+ *     export HF_TOKEN=your-token
+ *     export MODEL=<model-that-returns-encrypted-reasoning>
+ *     npx ts-node encrypted-reasoning.ts
+ */
+
+import OpenAI from "openai";
+
+// Configure client with HuggingFace router endpoint
+const client = new OpenAI({
+  baseURL: "https://router.huggingface.co/v1",
+  apiKey: process.env.HF_TOKEN,
+});
+
+/**
+ * Collect encrypted reasoning items so they can be sent back as input.
+ * Item ids and order are preserved, since the provider matches
+ * reasoning items by id when it decrypts them.
+ */
+function collectEncryptedReasoning(
+  response: OpenAI.Responses.Response
+): OpenAI.Responses.ResponseInputItem[] {
+  return response.output.filter(
+    // @ts-ignore - encrypted_content may exist on reasoning items
+    (item) => item.type === "reasoning" && item.encrypted_content
+  ) as OpenAI.Responses.ResponseInputItem[];
+}
+
+/**
+ * Send one stateless turn, asking for encrypted reasoning back
+ */
+async function createTurn(
+  model: string,
+  input: OpenAI.Responses.ResponseInputItem[]
+): Promise<OpenAI.Responses.Response> {
+  if (input.length === 0) {
+    throw new Error("Input must not be empty");
+  }
+
+  const response = await client.responses.create({
+    model,
+    instructions: "You are a helpful assistant.",
+    input,
+    reasoning: { effort: "medium" },
+    include: ["reasoning.encrypted_content"],
+    store: false,
+  });
+
+  return response;
+}
+
+// Main execution
+async function main(): Promise<void> {
+  // No default: open-weight models expose RAW reasoning and can't show the round trip
+  const model = process.env.MODEL;
+
+  if (!process.env.HF_TOKEN) {
+    console.error("Error: HF_TOKEN environment variable required");
+    process.exit(1);
+  }
+
+  if (!model) {
+    console.error("Error: MODEL environment variable required");
+    console.error("Set it to a model that returns encrypted reasoning (encrypted_content).");
+    process.exit(1);
+  }
+
+  console.log(`Using model: ${model}`);
+  console.log(`Endpoint: https://router.huggingface.co/v1/responses`);
+
+  const firstQuestion: OpenAI.Responses.ResponseInputItem = {
+    role: "user",
+    content: "Pick a prime number between 50 and 100 and explain why it is prime.",
+  };
+  const followUp: OpenAI.Responses.ResponseInputItem = {
+    role: "user",
+    content: "Now double it and tell me whether the result is prime.",
+  };
+
+  try {
+    // Turn 1
+    const first = await createTurn(model, [firstQuestion]);
+    const reasoningItems = collectEncryptedReasoning(first);
+
+    console.log(`\n${"=".repeat(60)}`);
+    console.log("TURN 1");
+    console.log(`${"=".repeat(60)}`);
+    console.log(`Encrypted reasoning items: ${reasoningItems.length}`);
+    console.log(first.output_text);
+
+    if (reasoningItems.length === 0) {
+      console.log("\nNo encrypted reasoning returned - this model exposes raw or summary reasoning instead.");
+      console.log("Set MODEL to a model that returns encrypted_content to see the round trip.");
+    }
+
+    // Turn 2: replay the prior turn, including its encrypted reasoning
+    const nextInput: OpenAI.Responses.ResponseInputItem[] = [
+      firstQuestion,
+      ...reasoningItems,
+      { role: "assistant", content: first.output_text },
+      followUp,
+    ];
+    const second = await createTurn(model, nextInput);
+
+    console.log(`\n${"=".repeat(60)}`);
+    console.log("TURN 2");
+    console.log(`${"=".repeat(60)}`);
+    console.log(second.output_text);
+  } catch (error) {
+    console.error("Error:", error);
+    process.exit(1);
+  }
+}
+
+main();
@@ -61,6 +61,10 @@ const PROVIDERS: ProviderInfo[] = [
  * Create an agent with a specific model (provider specified via suffix)
  */
 async function createAgent(model: string, input: string, instructions?: string) {
+  if (!input.trim()) {
+    throw new Error("Input must not be empty");
+  }
+
   const response = await client.responses.create({
     model,
     instructions: instructions || "You are a helpful assistant.",
@@ -70,10 +74,55 @@ async function createAgent(model: string, input: string, instructions?: string)
   return response;
 }
 
+/**
+ * Truncate text to at most maxChars characters, marking the cut with an ellipsis.
+ * Counts code points so emoji and other surrogate pairs are never split.
+ */
+function truncateText(text: string, maxChars: number): string {
+  const chars = Array.from(text);
+  if (chars.length <= maxChars) {
+    return text;
+  }
+  return chars.slice(0, maxChars).join("") + "…";
+}
+
+/**
+ * Truncation limits for the provider comparison output (null = no limit)
+ */
+interface DisplayOptions {
+  reasoningMax: number | null;
+  toolResultMax: number | null;
+  messageMax: number | null;
+  truncate: boolean; // false shows everything
+}
+
+const DEFAULT_DISPLAY_OPTIONS: DisplayOptions = {
+  reasoningMax: 150,
+  toolResultMax: null,
+  messageMax: 300,
+  truncate: true,
+};
+
+/**
+ * Apply a display limit unless truncation is disabled
+ */
+function fitText(text: string, maxChars: number | null, options: DisplayOptions): string {
+  if (!options.truncate || maxChars === null) {
+    return text;
+  }
+  return truncateText(text, maxChars);
+}
+
 /**
  * Compare the same prompt across different providers
  */
-async function compareProviders(prompt: string, models: string[]): Promise<void> {
+async function compareProviders(
+  prompt: string,
+  models: string[],
+  displayOptions: Partial<DisplayOptions> = {}
+): Promise<void> {
+  const options: DisplayOptions = { ...DEFAULT_DISPLAY_OPTIONS, ...displayOptions };
+
   console.log("\n" + "=".repeat(70));
   console.log("MULTI-PROVIDER COMPARISON");
   console.log("=".repeat(70));
@@ -111,14 +160,14 @@ async function compareProviders(prompt: string, models: string[]): Promise<void>
         for (const item of reasoningItems) {
           // @ts-ignore
           const text = item.content || item.summary || "[no content]";
-          console.log(`  - ${text.substring(0, 150)}${text.length > 150 ? "..." : ""}`);
+          console.log(`  - ${fitText(text, options.reasoningMax, options)}`);
         }
       }
 
       // Display final response using convenience helper
       console.log(`\nResponse:`);
       const outputText = response.output_text || "";
-      console.log(`  ${outputText.substring(0, 300)}${outputText.length > 300 ? "..." : ""}`);
+      console.log(`  ${fitText(outputText, options.messageMax, options)}`);
 
       results.push({ model, response, duration });
     } catch (error) {
@@ -147,9 +196,11 @@ async function compareProviders(prompt: string, models: string[]): Promise<void>
 }
 
 /**
- * Demonstrate provider switching via model suffix
+ * Demonstrate provider switching via model suffix (reasoning defaults to 100 chars here)
  */
-async function demonstrateProviderSwitching(): Promise<void> {
+async function demonstrateProviderSwitching(displayOptions: Partial<DisplayOptions> = {}): Promise<void> {
+  const options: DisplayOptions = { ...DEFAULT_DISPLAY_OPTIONS, reasoningMax: 100, ...displayOptions };
+
   console.log("\n" + "=".repeat(70));
   console.log("PROVIDER SWITCHING DEMONSTRATION");
   console.log("=".repeat(70));
@@ -177,7 +228,7 @@ async function demonstrateProviderSwitching(): Promise<void> {
       case "reasoning":
         // @ts-ignore
         const text = item.content || item.summary || "[no content]";
-        console.log(`  [REASONING] ${text.substring(0, 100)}...`);
+        console.log(`  [REASONING] ${fitText(text, options.reasoningMax, options)}`);
         break;
       case "message":
         // @ts-ignore
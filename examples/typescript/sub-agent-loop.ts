@@ -18,6 +18,59 @@ const client = new OpenAI({
   apiKey: process.env.HF_TOKEN,
 });
 
+/**
+ * How much the display functions print (VERBOSITY env var)
+ */
+enum Verbosity {
+  QUIET = 0, // Final output text only
+  NORMAL = 1, // Response items, long text truncated
+  VERBOSE = 2, // Full reasoning and tool results
+  TRACE = 3, // Also dump each raw output item
+}
+
+/**
+ * Parse a VERBOSITY value (quiet, normal, verbose, trace), defaulting to NORMAL
+ */
+function parseVerbosity(value: string | undefined): Verbosity {
+  switch ((value || "").trim().toLowerCase()) {
+    case "quiet":
+      return Verbosity.QUIET;
+    case "verbose":
+      return Verbosity.VERBOSE;
+    case "trace":
+      return Verbosity.TRACE;
+    default:
+      return Verbosity.NORMAL;
+  }
+}
+
+/**
+ * Truncation limits for displayExecutionTrace (null = no limit)
+ */
+interface DisplayOptions {
+  reasoningMax: number | null;
+  toolResultMax: number | null;
+  messageMax: number | null;
+  truncate: boolean; // false shows everything
+}
+
+const DEFAULT_DISPLAY_OPTIONS: DisplayOptions = {
+  reasoningMax: 200,
+  toolResultMax: 200,
+  messageMax: null,
+  truncate: true,
+};
+
+/**
+ * Apply a display limit unless truncation is disabled
+ */
+function fitText(text: string, maxChars: number | null, options: DisplayOptions): string {
+  if (!options.truncate || maxChars === null) {
+    return text;
+  }
+  return truncateText(text, maxChars);
+}
+
 /**
  * Define tools for the agent
  * NOTE: Tools are defined at TOP LEVEL (name, description, parameters)
@@ -110,6 +163,18 @@ const tools: OpenAI.Responses.Tool[] = [
   },
 ];
 
+/**
+ * Truncate text to at most maxChars characters, marking the cut with an ellipsis.
+ * Counts code points so emoji and other surrogate pairs are never split.
+ */
+function truncateText(text: string, maxChars: number): string {
+  const chars = Array.from(text);
+  if (chars.length <= maxChars) {
+    return text;
+  }
+  return chars.slice(0, maxChars).join("") + "…";
+}
+
 /**
  * Create an agent with sub-agent loop capability
  */
@@ -118,9 +183,13 @@ async function createAgentWithTools(
   input: string,
   instructions?: string
 ) {
+  if (!input.trim()) {
+    throw new Error("Input must not be empty");
+  }
+
   console.log(`\n[REQUEST] Sending to HuggingFace router...`);
   console.log(`[MODEL] ${model}`);
-  console.log(`[INPUT] ${input.substring(0, 100)}...`);
+  console.log(`[INPUT] ${truncateText(input, 100)}`);
 
   const response = await client.responses.create({
     model,
@@ -134,15 +203,82 @@ async function createAgentWithTools(
 }
 
 /**
- * Display the complete execution trace
+ * Compute output throughput from token count and elapsed milliseconds
  */
-function displayExecutionTrace(response: OpenAI.Responses.Response): void {
+function tokensPerSecond(outputTokens: number, durationMs: number): number {
+  if (durationMs <= 0) {
+    return 0;
+  }
+  return outputTokens / (durationMs / 1000);
+}
+
+/**
+ * Item counts and token total for a response
+ */
+interface ResponseSummary {
+  reasoningSteps: number;
+  toolCalls: number;
+  toolResults: number;
+  messages: number;
+  totalTokens: number;
+}
+
+/**
+ * Count the item types in a response
+ */
+function summarizeResponse(response: OpenAI.Responses.Response): ResponseSummary {
+  const count = (type: string) => response.output.filter((item) => item.type === type).length;
+
+  return {
+    reasoningSteps: count("reasoning"),
+    toolCalls: count("function_call"),
+    toolResults: count("function_call_output"),
+    messages: count("message"),
+    totalTokens: (response.usage?.input_tokens || 0) + (response.usage?.output_tokens || 0),
+  };
+}
+
+/**
+ * Display the complete execution trace.
+ * Pass the request duration to also report latency and tokens/sec.
+ * Display options override the default truncation limits; VERBOSE and above never truncate.
+ */
+function displayExecutionTrace(
+  response: OpenAI.Responses.Response,
+  durationMs?: number,
+  verbosity: Verbosity = Verbosity.NORMAL,
+  displayOptions: Partial<DisplayOptions> = {}
+): void {
+  if (verbosity === Verbosity.QUIET) {
+    console.log(response.output_text);
+    return;
+  }
+
+  const options: DisplayOptions = { ...DEFAULT_DISPLAY_OPTIONS, ...displayOptions };
+  if (verbosity >= Verbosity.VERBOSE) {
+    options.truncate = false;
+  }
+
   console.log(`\n${"=".repeat(60)}`);
   console.log(`EXECUTION TRACE - ${response.id}`);
   console.log(`${"=".repeat(60)}`);
+
+  // One-line summary first; it carries the token and latency figures
+  const summary = summarizeResponse(response);
+  let wallTime = "";
+  if (durationMs !== undefined) {
+    wallTime = `, ${durationMs}ms`;
+    if (response.usage) {
+      wallTime += ` (${tokensPerSecond(response.usage.output_tokens, durationMs).toFixed(1)} tokens/sec)`;
+    }
+  }
+  console.log(
+    `Summary: ${summary.reasoningSteps} reasoning steps, ${summary.toolCalls} tool calls, ` +
+      `${summary.toolResults} tool results, ${summary.messages} messages, ` +
+      `${summary.totalTokens} tokens (${response.usage?.input_tokens || 0} in / ${response.usage?.output_tokens || 0} out)${wallTime}`
+  );
   console.log(`Model: ${response.model}`);
   console.log(`Total output items: ${response.output.length}`);
-  console.log(`Tokens: ${response.usage?.input_tokens || 0} in / ${response.usage?.output_tokens || 0} out`);
   console.log(`${"=".repeat(60)}\n`);
 
   let toolCallCount = 0;
@@ -156,7 +292,7 @@ function displayExecutionTrace(response: OpenAI.Responses.Response): void {
         // @ts-ignore
         const reasoningText = item.content || item.summary || "[no content]";
         console.log(`${prefix} [REASONING]`);
-        console.log(`    ${reasoningText.substring(0, 200)}${reasoningText.length > 200 ? "..." : ""}`);
+        console.log(`    ${fitText(reasoningText, options.reasoningMax, options)}`);
         break;
 
       case "function_call":
@@ -173,19 +309,23 @@ function displayExecutionTrace(response: OpenAI.Responses.Response): void {
         console.log(`${prefix} [TOOL RESULT]`);
         // @ts-ignore
         const output = item.output || "";
-        console.log(`    Result: ${output.substring(0, 200)}${output.length > 200 ? "..." : ""}`);
+        console.log(`    Result: ${fitText(output, options.toolResultMax, options)}`);
         break;
 
       case "message":
         console.log(`${prefix} [FINAL RESPONSE]`);
         // @ts-ignore
-        console.log(`    ${item.content}`);
+        console.log(`    ${fitText(String(item.content), options.messageMax, options)}`);
         break;
 
       default:
         console.log(`${prefix} [${item.type.toUpperCase()}]`);
         console.log(`    ${JSON.stringify(item)}`);
     }
+
+    if (verbosity >= Verbosity.TRACE) {
+      console.log(`    Raw: ${JSON.stringify(item)}`);
+    }
     console.log();
   }
 
@@ -221,8 +361,11 @@ async function main(): Promise<void> {
   `;
 
   try {
+    const startTime = Date.now();
     const result = await createAgentWithTools(model, task);
-    displayExecutionTrace(result);
+    const duration = Date.now() - startTime;
+
+    displayExecutionTrace(result, duration, parseVerbosity(process.env.VERBOSITY));
   } catch (error) {
     console.error("Error:", error);
     process.exit(1);
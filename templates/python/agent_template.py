@@ -11,7 +11,9 @@ Usage:
 """
 
 import os
-from enum import Enum
+import time
+from dataclasses import dataclass
+from enum import Enum, IntEnum
 from openai import OpenAI
 
 
@@ -36,6 +38,9 @@ CONFIG = {
 
     # Timeout
     "timeout": 120.0,
+
+    # Display detail: quiet, normal, verbose, trace
+    "verbosity": os.environ.get("VERBOSITY", "normal"),
 }
 
 # Configure client with HuggingFace router endpoint
@@ -58,6 +63,34 @@ class ReasoningLevel(Enum):
     NONE = "none"         # No reasoning at all
 
 
+class Verbosity(IntEnum):
+    """How much the display functions print (VERBOSITY env var)."""
+    QUIET = 0     # Final output text only
+    NORMAL = 1    # Response items, long text truncated
+    VERBOSE = 2   # Full reasoning and tool results
+    TRACE = 3     # Also dump each raw output item
+
+
+def parse_verbosity(value: str) -> Verbosity:
+    """Parse a VERBOSITY value (quiet, normal, verbose, trace), defaulting to NORMAL."""
+    return Verbosity.__members__.get(value.strip().upper(), Verbosity.NORMAL)
+
+
+@dataclass
+class DisplayOptions:
+    """Truncation limits for display_response (None = no limit)."""
+    reasoning_max: int | None = 200
+    tool_result_max: int | None = 150
+    message_max: int | None = None
+    truncate: bool = True  # False shows everything
+
+    def fit(self, text: str, max_chars: int | None) -> str:
+        """Apply a limit unless truncation is disabled."""
+        if not self.truncate or max_chars is None:
+            return text
+        return truncate_text(text, max_chars)
+
+
 # =============================================================================
 # TOOLS - Define your agent's capabilities
 # =============================================================================
@@ -125,7 +158,14 @@ def create_agent(
 
     Returns:
         Response object
+
+    Raises:
+        ValueError: If the task is empty or whitespace-only
     """
+    # Providers reject empty input with a vague error; fail before the request
+    if not task.strip():
+        raise ValueError("Task must not be empty")
+
     request_params = {
         "model": CONFIG["model"],
         "instructions": instructions or "You are a helpful assistant that completes tasks step by step.",
@@ -175,14 +215,73 @@ def get_reasoning(item) -> tuple[ReasoningLevel, str]:
 # EXECUTION HELPERS
 # =============================================================================
 
-def display_response(response) -> None:
-    """Display the response in a readable format."""
+def truncate_text(text: str, max_chars: int) -> str:
+    """Truncate text to at most max_chars characters, marking the cut with an ellipsis."""
+    if len(text) <= max_chars:
+        return text
+    return text[:max_chars] + "…"
+
+
+def tokens_per_second(output_tokens: int, duration_ms: int) -> float:
+    """Compute output throughput from token count and elapsed milliseconds."""
+    if duration_ms <= 0:
+        return 0.0
+    return output_tokens / (duration_ms / 1000)
+
+
+def output_token_split(usage) -> tuple[int, int] | None:
+    """
+    Split output tokens into reasoning and text.
+
+    Args:
+        usage: Response usage, possibly None
+
+    Returns:
+        (reasoning_tokens, text_tokens), or None when the provider
+        doesn't report output_tokens_details
+    """
+    details = getattr(usage, "output_tokens_details", None)
+    reasoning = getattr(details, "reasoning_tokens", None)
+    if reasoning is None:
+        return None
+    return reasoning, max(usage.output_tokens - reasoning, 0)
+
+
+def display_response(
+    response,
+    duration_ms: int | None = None,
+    verbosity: Verbosity = Verbosity.NORMAL,
+    options: DisplayOptions | None = None,
+) -> None:
+    """
+    Display the response in a readable format.
+
+    Args:
+        response: The Open Responses response
+        duration_ms: Optional request latency, used to report tokens/sec
+        verbosity: How much detail to print
+        options: Truncation limits (VERBOSE and above never truncate)
+    """
+    if verbosity == Verbosity.QUIET:
+        print(response.output_text)
+        return
+
+    options = options or DisplayOptions()
+    if verbosity >= Verbosity.VERBOSE:
+        options = DisplayOptions(truncate=False)
+
     print(f"\n{'=' * 60}")
     print(f"Response ID: {response.id}")
     print(f"Model: {response.model}")
     in_tokens = response.usage.input_tokens if response.usage else 0
     out_tokens = response.usage.output_tokens if response.usage else 0
     print(f"Tokens: {in_tokens} in / {out_tokens} out")
+    split = output_token_split(response.usage)
+    if split:
+        print(f"  reasoning: {split[0]}")
+        print(f"  text: {split[1]}")
+    if duration_ms is not None and response.usage:
+        print(f"Latency: {duration_ms}ms ({tokens_per_second(out_tokens, duration_ms):.1f} tokens/sec)")
     print(f"{'=' * 60}\n")
 
     tool_call_count = 0
@@ -191,7 +290,7 @@ def display_response(response) -> None:
         match item.type:
             case "reasoning":
                 text = getattr(item, "content", None) or getattr(item, "summary", None) or "[encrypted]"
-                print(f"[REASONING] {text[:200]}{'...' if len(text) > 200 else ''}")
+                print(f"[REASONING] {options.fit(text, options.reasoning_max)}")
 
             case "function_call":
                 tool_call_count += 1
@@ -202,14 +301,17 @@ def display_response(response) -> None:
 
             case "function_call_output":
                 output = getattr(item, "output", "") or ""
-                print(f"[TOOL RESULT] {output[:150]}{'...' if len(output) > 150 else ''}")
+                print(f"[TOOL RESULT] {options.fit(output, options.tool_result_max)}")
 
             case "message":
-                print(f"[RESPONSE] {getattr(item, 'content', '')}")
+                print(f"[RESPONSE] {options.fit(str(getattr(item, 'content', '')), options.message_max)}")
 
             case _:
                 print(f"[{item.type.upper()}] {item}")
 
+        if verbosity >= Verbosity.TRACE:
+            print(f"  Raw: {item.model_dump_json()}")
+
         print()
 
     # Also show convenience helper output
@@ -246,9 +348,11 @@ def run_agent(task: str, use_tools: bool = False):
     print("Processing...\n")
 
     tools = TOOLS if use_tools else None
+    start_time = time.time()
     response = create_agent(task, tools=tools)
+    duration = int((time.time() - start_time) * 1000)
 
-    display_response(response)
+    display_response(response, duration, parse_verbosity(CONFIG["verbosity"]))
 
     return response
 
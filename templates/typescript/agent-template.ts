@@ -19,6 +19,32 @@ import OpenAI from "openai";
 // CONFIGURATION
 // ============================================================
 
+/**
+ * How much the display functions print (VERBOSITY env var)
+ */
+enum Verbosity {
+  QUIET = 0, // Final output text only
+  NORMAL = 1, // Response items, long text truncated
+  VERBOSE = 2, // Full reasoning and tool results
+  TRACE = 3, // Also dump each raw output item
+}
+
+/**
+ * Parse a VERBOSITY value (quiet, normal, verbose, trace), defaulting to NORMAL
+ */
+function parseVerbosity(value: string | undefined): Verbosity {
+  switch ((value || "").trim().toLowerCase()) {
+    case "quiet":
+      return Verbosity.QUIET;
+    case "verbose":
+      return Verbosity.VERBOSE;
+    case "trace":
+      return Verbosity.TRACE;
+    default:
+      return Verbosity.NORMAL;
+  }
+}
+
 const CONFIG = {
   // HuggingFace token (required)
   apiKey: process.env.HF_TOKEN || "",
@@ -37,8 +63,18 @@ const CONFIG = {
   // Reasoning configuration
   reasoningEffort: (process.env.REASONING_EFFORT || "medium") as "low" | "medium" | "high",
 
-  // Logging
-  verbose: process.env.VERBOSE === "true",
+  // Display detail: quiet, normal, verbose, trace
+  // (legacy VERBOSE=true maps to normal, which prints the same truncated items)
+  verbosity: parseVerbosity(process.env.VERBOSITY || "normal"),
+
+  // Kept for existing importers: true when reasoning and tool results are shown.
+  // Setting it to false now means quiet.
+  get verbose(): boolean {
+    return this.verbosity >= Verbosity.NORMAL;
+  },
+  set verbose(value: boolean) {
+    this.verbosity = value ? Math.max(this.verbosity, Verbosity.NORMAL) : Verbosity.QUIET;
+  },
 };
 
 // ============================================================
@@ -112,7 +148,12 @@ async function runAgent(
     throw new Error("HF_TOKEN environment variable is required");
   }
 
-  if (CONFIG.verbose) {
+  // Providers reject empty input with a vague error; fail before the request
+  if (!task.trim()) {
+    throw new Error("Task must not be empty");
+  }
+
+  if (CONFIG.verbosity >= Verbosity.NORMAL) {
     console.log(`[CONFIG] Endpoint: https://router.huggingface.co/v1/responses`);
     console.log(`[CONFIG] Model: ${CONFIG.model}`);
     console.log(`[CONFIG] Reasoning Effort: ${CONFIG.reasoningEffort}`);
@@ -136,9 +177,89 @@ async function runAgent(
 // ============================================================
 
 /**
- * Process and display agent response
+ * Truncate text to at most maxChars characters, marking the cut with an ellipsis.
+ * Counts code points so emoji and other surrogate pairs are never split.
  */
-function processResponse(response: OpenAI.Responses.Response): void {
+function truncateText(text: string, maxChars: number): string {
+  const chars = Array.from(text);
+  if (chars.length <= maxChars) {
+    return text;
+  }
+  return chars.slice(0, maxChars).join("") + "…";
+}
+
+/**
+ * Truncation limits for processResponse (null = no limit)
+ */
+interface DisplayOptions {
+  reasoningMax: number | null;
+  toolResultMax: number | null;
+  messageMax: number | null;
+  truncate: boolean; // false shows everything
+}
+
+const DEFAULT_DISPLAY_OPTIONS: DisplayOptions = {
+  reasoningMax: 200,
+  toolResultMax: 200,
+  messageMax: null,
+  truncate: true,
+};
+
+/**
+ * Apply a display limit unless truncation is disabled
+ */
+function fitText(text: string, maxChars: number | null, options: DisplayOptions): string {
+  if (!options.truncate || maxChars === null) {
+    return text;
+  }
+  return truncateText(text, maxChars);
+}
+
+/**
+ * Compute output throughput from token count and elapsed milliseconds
+ */
+function tokensPerSecond(outputTokens: number, durationMs: number): number {
+  if (durationMs <= 0) {
+    return 0;
+  }
+  return outputTokens / (durationMs / 1000);
+}
+
+/**
+ * Split output tokens into reasoning and text.
+ * Returns null when the provider doesn't report output_tokens_details.
+ */
+function outputTokenSplit(
+  usage?: OpenAI.Responses.ResponseUsage
+): { reasoning: number; text: number } | null {
+  const reasoning = usage?.output_tokens_details?.reasoning_tokens;
+  if (reasoning === undefined || reasoning === null) {
+    return null;
+  }
+  return { reasoning, text: Math.max(usage!.output_tokens - reasoning, 0) };
+}
+
+/**
+ * Process and display agent response.
+ * Pass the request duration to also report latency and tokens/sec.
+ * Display options override the default truncation limits; VERBOSE and above never truncate.
+ */
+function processResponse(
+  response: OpenAI.Responses.Response,
+  durationMs?: number,
+  verbosity: Verbosity = CONFIG.verbosity,
+  displayOptions: Partial<DisplayOptions> = {}
+): void {
+  if (verbosity === Verbosity.QUIET) {
+    console.log(response.output_text);
+    return;
+  }
+
+  const options: DisplayOptions = { ...DEFAULT_DISPLAY_OPTIONS, ...displayOptions };
+  if (verbosity >= Verbosity.VERBOSE) {
+    options.truncate = false;
+  }
+
   console.log("\n" + "=".repeat(60));
   console.log("AGENT RESPONSE");
   console.log("=".repeat(60));
@@ -152,38 +273,37 @@ function processResponse(response: OpenAI.Responses.Response): void {
   for (const item of response.output) {
     switch (item.type) {
       case "reasoning":
-        if (CONFIG.verbose) {
-          // @ts-ignore
-          const text = item.summary || item.content || "[encrypted]";
-          console.log(`[REASONING] ${text.substring(0, 200)}${text.length > 200 ? "..." : ""}`);
-        }
+        // @ts-ignore
+        const text = item.summary || item.content || "[encrypted]";
+        console.log(`[REASONING] ${fitText(text, options.reasoningMax, options)}`);
         break;
 
       case "function_call":
         toolCallCount++;
         // @ts-ignore
         console.log(`[TOOL CALL #${toolCallCount}] ${item.name}`);
-        if (CONFIG.verbose) {
-          // @ts-ignore
-          console.log(`  Arguments: ${JSON.stringify(item.arguments)}`);
-        }
+        // @ts-ignore
+        console.log(`  Arguments: ${JSON.stringify(item.arguments)}`);
         break;
 
       case "function_call_output":
-        if (CONFIG.verbose) {
-          // @ts-ignore
-          console.log(`[TOOL RESULT] ${item.output?.substring(0, 200)}...`);
-        }
+        // @ts-ignore
+        const output = item.output || "";
+        console.log(`[TOOL RESULT] ${fitText(output, options.toolResultMax, options)}`);
         break;
 
       case "message":
         // @ts-ignore
-        console.log(`\n[FINAL RESPONSE]\n${item.content}`);
+        console.log(`\n[FINAL RESPONSE]\n${fitText(String(item.content), options.messageMax, options)}`);
         break;
 
       default:
         console.log(`[${item.type.toUpperCase()}]`, item);
     }
+
+    if (verbosity >= Verbosity.TRACE) {
+      console.log(`  Raw: ${JSON.stringify(item)}`);
+    }
   }
 
   // Also show convenience helper output
@@ -196,6 +316,14 @@ function processResponse(response: OpenAI.Responses.Response): void {
   console.log("\n" + "─".repeat(60));
   console.log(`Tool Calls: ${toolCallCount}`);
   console.log(`Tokens: ${response.usage?.input_tokens || 0} in / ${response.usage?.output_tokens || 0} out`);
+  const split = outputTokenSplit(response.usage);
+  if (split) {
+    console.log(`  reasoning: ${split.reasoning}`);
+    console.log(`  text: ${split.text}`);
+  }
+  if (durationMs !== undefined && response.usage) {
+    console.log(`Latency: ${durationMs}ms (${tokensPerSecond(response.usage.output_tokens, durationMs).toFixed(1)} tokens/sec)`);
+  }
 }
 
 // ============================================================
@@ -219,8 +347,11 @@ async function main(): Promise<void> {
   const instructions = "You are a helpful assistant that completes tasks step by step.";
 
   try {
+    const startTime = Date.now();
     const response = await runAgent(task, instructions);
-    processResponse(response);
+    const duration = Date.now() - startTime;
+
+    processResponse(response, duration);
   } catch (error) {
     console.error("Error:", error);
     process.exit(1);
@@ -231,4 +362,5 @@ async function main(): Promise<void> {
 main();
 
 // Export for use as a module
-export { runAgent, processResponse, CONFIG, tools, executeTool, client };
+export { runAgent, processResponse, tokensPerSecond, Verbosity, CONFIG, tools, executeTool, client };
+export type { DisplayOptions };